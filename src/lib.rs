@@ -20,6 +20,42 @@
 //! - By default, the generated function has the same visibility as the struct.
 //!   To override this behaviour, the struct can be marked with e. g.
 //!   `#[constructor(visibility = "pub(super)")]`.
+//! - To generate more than one constructor, the struct can be marked with
+//!   repeated `#[constructor(ctor(name = "..."))]` attributes, each optionally
+//!   followed by its own `visibility`. `required`/`default` on a field can then
+//!   be scoped to specific constructors with e. g.
+//!   `#[constructor(required(from_title))]`, so the same field can be a
+//!   required argument in one constructor and `Default::default()`-filled in
+//!   another. A bare `#[constructor(required)]`/`#[constructor(default)]`
+//!   still applies to every generated constructor.
+//! - To accept any type that converts into a required field's type instead of
+//!   the field's exact type, it can be marked with `#[constructor(into)]`. The
+//!   struct itself can be marked with `#[constructor(into)]` to apply this to
+//!   every required field at once.
+//! - To fill a defaulted field with something other than `Default::default()`,
+//!   it can be marked with `#[constructor(default = "expr")]`, where `expr` is
+//!   parsed as a Rust expression, e. g.
+//!   `#[constructor(default = "Vec::with_capacity(8)")]`. This also works for
+//!   fields whose type doesn't implement [`Default`].
+//! - To validate the struct's invariants while constructing it, mark a field
+//!   with `#[constructor(try_into)]` to accept any type implementing
+//!   `TryInto<T>` instead of `T` (its conversion error must convert into `E`
+//!   via `From`), and/or mark the struct with
+//!   `#[constructor(validate = "path::to::fn")]` where the function has the
+//!   signature `fn(&Self) -> Result<(), E>`. Both require a
+//!   `#[constructor(error = "E")]` on the struct and generate an additional
+//!   fallible constructor (`try_new` by default, overridable with
+//!   `#[constructor(try_name = "...")]`) returning `Result<Self, E>`, next to
+//!   the regular, non-fallible constructor(s).
+//! - If every field is either `Option<T>` or marked `#[constructor(default)]`,
+//!   i. e. the primary constructor takes no arguments, the struct can be
+//!   marked with `#[constructor(default)]` to additionally derive [`Default`]
+//!   by routing it through that constructor.
+//! - Tuple structs are supported as well; fields are addressed by position
+//!   (`field0`, `field1`, ...) and the constructor is built with `Self(..)`.
+//! - Enums are supported by generating one constructor per variant, named
+//!   `new_<variant>` (snake-cased) by default or overridden per-variant with
+//!   `#[constructor(name = "...")]` on that variant.
 //!
 //! For more advanced uses you might prefer using
 //! [`derive-new`](https://lib.rs/crates/derive-new) or
@@ -42,10 +78,47 @@
 //! )
 //! ```
 
-use darling::{ast::Data, util::Flag, Error, FromDeriveInput, FromField};
-use proc_macro2::Span;
+use darling::{
+    ast::{Data, Style},
+    util::{Flag, Override, PathList},
+    Error, FromDeriveInput, FromField, FromMeta, FromVariant,
+};
+use proc_macro2::{Span, TokenStream};
 use quote::quote;
-use syn::{parse_macro_input, Generics, Ident, Path, Type, Visibility};
+use syn::{parse_macro_input, Expr, Generics, Ident, Path, Type, Visibility};
+
+/// A single named constructor to generate, e. g. declared as
+/// `#[constructor(ctor(name = "from_title"))]`.
+#[derive(Debug, Clone, FromMeta)]
+struct ConstructorDescriptor {
+    name: Ident,
+    visibility: Option<Visibility>,
+}
+
+/// The value of a field's `#[constructor(default)]` attribute: either a bare
+/// flag or constructor list (fills the field with `Default::default()`), or
+/// `= "expr"` (fills the field with a custom expression).
+#[derive(Debug, Clone)]
+enum DefaultValue {
+    All,
+    Only(PathList),
+    Expr(Expr),
+}
+impl FromMeta for DefaultValue {
+    fn from_word() -> darling::Result<Self> {
+        Ok(Self::All)
+    }
+
+    fn from_list(items: &[darling::ast::NestedMeta]) -> darling::Result<Self> {
+        PathList::from_list(items).map(Self::Only)
+    }
+
+    fn from_string(value: &str) -> darling::Result<Self> {
+        syn::parse_str(value)
+            .map(Self::Expr)
+            .map_err(|e| Error::custom(e.to_string()))
+    }
+}
 
 #[derive(Debug, FromField)]
 #[darling(attributes(constructor), and_then = "Self::not_both")]
@@ -53,96 +126,399 @@ struct Field {
     ident: Option<Ident>,
     ty: Type,
 
-    required: Flag,
-    default: Flag,
+    #[darling(default)]
+    required: Option<Override<PathList>>,
+    #[darling(default)]
+    default: Option<DefaultValue>,
+    into: Flag,
+    try_into: Flag,
 }
 impl Field {
     fn not_both(self) -> darling::Result<Self> {
-        if self.required.is_present() && self.default.is_present() {
-            Err(
-                Error::custom("Field cannot use `required` and `default`at the same time.")
-                    .with_span(&self.default),
+        let default_is_all = matches!(self.default, Some(DefaultValue::All | DefaultValue::Expr(_)));
+        let overlaps = match (&self.required, &self.default) {
+            (Some(Override::Inherit), Some(_)) => true,
+            (Some(_), Some(_)) if default_is_all => true,
+            (Some(Override::Explicit(required)), Some(DefaultValue::Only(default))) => required
+                .iter()
+                .any(|required| default.iter().any(|default| default == required)),
+            _ => false,
+        };
+        if overlaps {
+            Err(Error::custom(
+                "Field cannot use `required` and `default` for the same constructor at the same time.",
             )
+            .with_span(&self.ident))
         } else {
             Ok(self)
         }
     }
+
+    /// Whether this field is an explicit argument of the constructor named `ctor`.
+    fn is_required_for(&self, ctor: &Ident) -> bool {
+        match &self.required {
+            None => false,
+            Some(Override::Inherit) => true,
+            Some(Override::Explicit(ctors)) => ctors.iter().any(|path| path.is_ident(ctor)),
+        }
+    }
+
+    /// Whether this field is explicitly defaulted by the constructor named `ctor`.
+    fn is_default_for(&self, ctor: &Ident) -> bool {
+        match &self.default {
+            None => false,
+            Some(DefaultValue::All | DefaultValue::Expr(_)) => true,
+            Some(DefaultValue::Only(ctors)) => ctors.iter().any(|path| path.is_ident(ctor)),
+        }
+    }
+
+    /// The initializer to use for this field when it is defaulted, i. e. when
+    /// [`Field::is_default_for`] returns `true`.
+    fn default_init(&self) -> TokenStream {
+        match &self.default {
+            Some(DefaultValue::Expr(expr)) => quote!(#expr),
+            _ => quote!(Default::default()),
+        }
+    }
+}
+
+/// The result of classifying a set of fields for one constructor: its
+/// generic parameters and where-clause bounds (used by fallible
+/// `#[constructor(try_into)]` fields, which get one generic argument type
+/// each), its argument list, the `let`-bindings that must run before the
+/// value is built, and the field initializers, paired with the field's ident
+/// for named fields (`None` for tuple fields).
+struct BuiltFields {
+    generic_params: Vec<Ident>,
+    where_predicates: Vec<TokenStream>,
+    arguments: Vec<TokenStream>,
+    prelude: Vec<TokenStream>,
+    field_inits: Vec<(Option<Ident>, TokenStream)>,
+}
+
+/// An enum variant to generate a constructor for, e. g.
+/// `#[constructor(name = "from_runtime")]` on the variant itself.
+#[derive(Debug, FromVariant)]
+#[darling(attributes(constructor))]
+struct Variant {
+    ident: Ident,
+    fields: darling::ast::Fields<Field>,
+
+    name: Option<Ident>,
 }
 
 #[derive(Debug, FromDeriveInput)]
-#[darling(attributes(constructor), supports(struct_named))]
+#[darling(
+    attributes(constructor),
+    supports(struct_named, struct_newtype, struct_tuple, enum_any),
+    and_then = "Self::no_duplicate_names"
+)]
 struct ConstructorLite {
     vis: Visibility,
     ident: Ident,
     generics: Generics,
-    data: Data<(), Field>,
+    data: Data<Variant, Field>,
 
     visibility: Option<Visibility>,
     name: Option<Ident>,
+    #[darling(multiple, rename = "ctor")]
+    constructors: Vec<ConstructorDescriptor>,
+    into: Flag,
+
+    try_name: Option<Ident>,
+    error: Option<Type>,
+    validate: Option<Path>,
+
+    #[darling(rename = "default")]
+    derive_default: Flag,
 }
 impl ConstructorLite {
+    fn no_duplicate_names(self) -> darling::Result<Self> {
+        let mut seen = Vec::new();
+        for descriptor in &self.constructors {
+            if seen.contains(&&descriptor.name) {
+                return Err(Error::custom(format!(
+                    "Constructor `{}` is declared more than once.",
+                    descriptor.name
+                ))
+                .with_span(&descriptor.name));
+            }
+            seen.push(&descriptor.name);
+        }
+        Ok(self)
+    }
+
+    /// The constructors to generate, falling back to a single `new()` (or the
+    /// struct-level `name`/`visibility` override) when none were declared
+    /// explicitly.
+    fn descriptors(&self) -> Vec<ConstructorDescriptor> {
+        if self.constructors.is_empty() {
+            vec![ConstructorDescriptor {
+                name: self
+                    .name
+                    .clone()
+                    .unwrap_or_else(|| Ident::new("new", Span::call_site())),
+                visibility: self.visibility.clone(),
+            }]
+        } else {
+            self.constructors.clone()
+        }
+    }
+
+    /// Classify every field for the constructor named `ctor`. `error` is the
+    /// fallible constructor's error type; it is only consulted for
+    /// `#[constructor(try_into)]` fields and must be `Some` whenever `fallible`
+    /// is `true`.
+    fn build_fields(
+        &self,
+        fields: &darling::ast::Fields<Field>,
+        ctor: &Ident,
+        fallible: bool,
+        error: Option<&Type>,
+    ) -> BuiltFields {
+        let mut generic_params = Vec::new();
+        let mut where_predicates = Vec::new();
+        let mut arguments = Vec::new();
+        let mut prelude = Vec::new();
+        let mut field_inits = Vec::new();
+
+        for (index, field) in fields.iter().enumerate() {
+            let Field {
+                ident,
+                ty,
+                into,
+                try_into,
+                ..
+            } = field;
+            // Tuple fields have no ident, so positional arguments are named
+            // `field0`, `field1`, ... instead.
+            let arg_ident = ident
+                .clone()
+                .unwrap_or_else(|| Ident::new(&format!("field{index}"), Span::call_site()));
+
+            if fallible && try_into.is_present() {
+                // Each `try_into` field gets its own generic argument type
+                // with an explicit `TryInto`/`From` bound, rather than a bare
+                // `impl TryInto<T>` — that would leave the intermediate error
+                // type of `.try_into()?` unconstrained.
+                let generic = Ident::new(&format!("Arg{index}"), Span::call_site());
+                let error = error.expect("fallible build_fields call without an error type");
+
+                arguments.push(quote!(#arg_ident: #generic));
+                generic_params.push(generic.clone());
+                where_predicates.push(quote!(
+                    #generic: ::core::convert::TryInto<#ty>,
+                    #error: ::core::convert::From<<#generic as ::core::convert::TryInto<#ty>>::Error>,
+                ));
+                prelude.push(quote!(
+                    let #arg_ident: #ty = ::core::convert::TryInto::try_into(#arg_ident)?;
+                ));
+                field_inits.push((ident.clone(), quote!(#arg_ident)));
+                continue;
+            }
+
+            let is_required = if field.is_required_for(ctor) {
+                true
+            } else if field.is_default_for(ctor) {
+                field_inits.push((ident.clone(), field.default_init()));
+                continue;
+            } else if let Type::Path(ty) = &ty {
+                !path_is_option(&ty.path)
+            } else {
+                continue;
+            };
+
+            if !is_required {
+                field_inits.push((ident.clone(), quote!(Default::default())));
+                continue;
+            }
+
+            if into.is_present() || self.into.is_present() {
+                arguments.push(quote!(#arg_ident: impl ::core::convert::Into<#ty>));
+                field_inits.push((ident.clone(), quote!(#arg_ident.into())));
+            } else {
+                arguments.push(quote!(#arg_ident: #ty));
+                field_inits.push((ident.clone(), quote!(#arg_ident)));
+            }
+        }
+
+        BuiltFields {
+            generic_params,
+            where_predicates,
+            arguments,
+            prelude,
+            field_inits,
+        }
+    }
+
+    /// Whether a fallible `try_new`-style constructor was requested, either
+    /// via a field-level `#[constructor(try_into)]` or a struct-level
+    /// `#[constructor(validate = "...")]`.
+    fn fallible_requested(&self, fields: &darling::ast::Fields<Field>) -> bool {
+        self.validate.is_some() || fields.iter().any(|field| field.try_into.is_present())
+    }
+
     fn constructor(&self) -> darling::Result<proc_macro::TokenStream> {
         let Self {
             vis,
             ident,
             generics,
             data,
-            visibility,
-            name,
+            ..
         } = self;
 
-        let Data::Struct(fields) = data else {
-            return Err(Error::custom("ConstructorLite supports only structs."));
-        };
+        match data {
+            Data::Struct(fields) => self.struct_constructor(vis, ident, generics, fields),
+            Data::Enum(variants) => self.enum_constructor(vis, ident, generics, variants),
+        }
+    }
 
-        let mut arguments = Vec::new();
-        let mut required_field_idents = Vec::new();
-        let mut optional_field_idents = Vec::new();
+    fn struct_constructor(
+        &self,
+        vis: &Visibility,
+        ident: &Ident,
+        generics: &Generics,
+        fields: &darling::ast::Fields<Field>,
+    ) -> darling::Result<proc_macro::TokenStream> {
+        let mut functions = Vec::new();
 
-        for Field {
-            ident,
-            ty,
-            required,
-            default,
-        } in fields.iter()
-        {
-            if required.is_present() {
-                arguments.push(quote!(#ident: #ty));
-                required_field_idents.push(ident);
-                continue;
-            }
-            if default.is_present() {
-                optional_field_idents.push(ident);
-                continue;
-            }
+        for descriptor in self.descriptors() {
+            let ConstructorDescriptor { name, visibility } = &descriptor;
+
+            let BuiltFields {
+                arguments,
+                field_inits,
+                ..
+            } = self.build_fields(fields, name, false, None);
+
+            let fn_vis = visibility.as_ref().unwrap_or(vis);
+            let body = construct(quote!(Self), fields.style, &field_inits);
 
-            if let Type::Path(ty) = &ty {
-                if path_is_option(&ty.path) {
-                    optional_field_idents.push(ident);
-                } else {
-                    arguments.push(quote!(#ident: #ty));
-                    required_field_idents.push(ident);
+            functions.push(quote!(
+                #fn_vis fn #name ( #( #arguments ),* ) -> Self {
+                    #body
                 }
-            }
+            ));
+        }
+
+        if self.fallible_requested(fields) {
+            let try_name = self
+                .try_name
+                .clone()
+                .unwrap_or_else(|| Ident::new("try_new", Span::call_site()));
+            let error = self.error.as_ref().ok_or_else(|| {
+                Error::custom(
+                    "`#[constructor(error = \"...\")]` is required when using `try_into` or `validate`.",
+                )
+            })?;
+
+            let BuiltFields {
+                generic_params,
+                where_predicates,
+                arguments,
+                prelude,
+                field_inits,
+            } = self.build_fields(fields, &try_name, true, Some(error));
+            let body = construct(quote!(Self), fields.style, &field_inits);
+
+            let validate = self.validate.as_ref().map(|validate| {
+                quote!(
+                    #validate(&value)?;
+                )
+            });
+
+            let fn_vis = self.visibility.as_ref().unwrap_or(vis);
+
+            functions.push(quote!(
+                #fn_vis fn #try_name<#(#generic_params),*> ( #( #arguments ),* ) -> ::core::result::Result<Self, #error>
+                where
+                    #( #where_predicates )*
+                {
+                    #( #prelude )*
+                    let value = #body;
+                    #validate
+                    ::core::result::Result::Ok(value)
+                }
+            ));
         }
 
-        let vis = visibility.as_ref().unwrap_or(vis);
-        let name: Ident = name
-            .clone()
-            .unwrap_or_else(|| Ident::new("new", Span::call_site()));
+        let default_impl = if self.derive_default.is_present() {
+            let primary = self
+                .descriptors()
+                .into_iter()
+                .next()
+                .expect("`descriptors()` always returns at least one descriptor");
+            let arguments = self.build_fields(fields, &primary.name, false, None).arguments;
+            if !arguments.is_empty() {
+                return Err(Error::custom(
+                    "`#[constructor(default)]` requires a constructor without required arguments.",
+                )
+                .with_span(ident));
+            }
+            let primary_name = &primary.name;
+            Some(quote!(
+                impl #generics ::core::default::Default for #ident #generics {
+                    fn default() -> Self {
+                        Self::#primary_name()
+                    }
+                }
+            ))
+        } else {
+            None
+        };
 
         let constructor = quote!(
             impl #generics #ident #generics {
-                #vis fn #name ( #( #arguments ),* ) -> Self {
-                    Self {
-                        #(
-                            #required_field_idents,
-                        )*
-                        #(
-                            #optional_field_idents: Default::default(),
-                        )*
-                    }
+                #( #functions )*
+            }
+
+            #default_impl
+        );
+
+        Ok(constructor.into())
+    }
+
+    fn enum_constructor(
+        &self,
+        vis: &Visibility,
+        ident: &Ident,
+        generics: &Generics,
+        variants: &[Variant],
+    ) -> darling::Result<proc_macro::TokenStream> {
+        let mut functions = Vec::new();
+
+        for Variant {
+            ident: variant_ident,
+            fields,
+            name,
+        } in variants
+        {
+            let fn_name = name.clone().unwrap_or_else(|| {
+                Ident::new(
+                    &format!("new_{}", to_snake_case(&variant_ident.to_string())),
+                    Span::call_site(),
+                )
+            });
+
+            let BuiltFields {
+                arguments,
+                field_inits,
+                ..
+            } = self.build_fields(fields, &fn_name, false, None);
+            let body = construct(quote!(#ident::#variant_ident), fields.style, &field_inits);
+
+            let fn_vis = self.visibility.as_ref().unwrap_or(vis);
+
+            functions.push(quote!(
+                #fn_vis fn #fn_name ( #( #arguments ),* ) -> Self {
+                    #body
                 }
+            ));
+        }
+
+        let constructor = quote!(
+            impl #generics #ident #generics {
+                #( #functions )*
             }
         );
 
@@ -150,6 +526,39 @@ impl ConstructorLite {
     }
 }
 
+/// Build a `Self { .. }`/`Self(..)`/`Self` value (or, for an enum variant, the
+/// equivalent `Enum::Variant` construction) out of its classified fields.
+fn construct(path: TokenStream, style: Style, inits: &[(Option<Ident>, TokenStream)]) -> TokenStream {
+    match style {
+        Style::Struct => {
+            let inits = inits.iter().map(|(ident, expr)| quote!(#ident: #expr));
+            quote!(#path { #( #inits, )* })
+        }
+        Style::Tuple => {
+            let inits = inits.iter().map(|(_, expr)| expr);
+            quote!(#path( #( #inits ),* ))
+        }
+        Style::Unit => quote!(#path),
+    }
+}
+
+/// Convert a `PascalCase` identifier (as used for enum variants) to
+/// `snake_case`.
+fn to_snake_case(ident: &str) -> String {
+    let mut result = String::with_capacity(ident.len() + 4);
+    for (i, ch) in ident.chars().enumerate() {
+        if ch.is_uppercase() {
+            if i != 0 {
+                result.push('_');
+            }
+            result.extend(ch.to_lowercase());
+        } else {
+            result.push(ch);
+        }
+    }
+    result
+}
+
 fn path_is_option(path: &Path) -> bool {
     // Option<T>
     if path.leading_colon.is_none()
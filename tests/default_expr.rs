@@ -0,0 +1,17 @@
+use constructor_lite::ConstructorLite;
+
+#[derive(Debug, PartialEq, ConstructorLite)]
+struct Playlist {
+    #[constructor(default = "\"untitled\".to_owned()")]
+    title: String,
+    #[constructor(default = "Vec::with_capacity(8)")]
+    tracks: Vec<String>,
+}
+
+#[test]
+fn test_default_expr() {
+    let playlist = Playlist::new();
+    assert_eq!(playlist.title, "untitled");
+    assert_eq!(playlist.tracks, Vec::<String>::new());
+    assert_eq!(playlist.tracks.capacity(), 8);
+}
@@ -0,0 +1,21 @@
+use constructor_lite::ConstructorLite;
+
+#[derive(Debug, PartialEq, ConstructorLite)]
+#[constructor(default)]
+struct Playlist {
+    #[constructor(default = "\"untitled\".to_owned()")]
+    title: String,
+    tracks: Option<Vec<String>>,
+}
+
+#[test]
+fn test_derive_default() {
+    assert_eq!(
+        Playlist::default(),
+        Playlist {
+            title: "untitled".to_owned(),
+            tracks: None
+        }
+    );
+    assert_eq!(Playlist::default(), Playlist::new());
+}
@@ -0,0 +1,18 @@
+use constructor_lite::ConstructorLite;
+
+#[derive(Debug, PartialEq, ConstructorLite)]
+enum Shape {
+    Circle {
+        radius: f64,
+    },
+    #[constructor(name = "rect")]
+    Rectangle(f64, f64),
+    Point,
+}
+
+#[test]
+fn test_enum_variants() {
+    assert_eq!(Shape::new_circle(1.0), Shape::Circle { radius: 1.0 });
+    assert_eq!(Shape::rect(2.0, 3.0), Shape::Rectangle(2.0, 3.0));
+    assert_eq!(Shape::new_point(), Shape::Point);
+}
@@ -0,0 +1,38 @@
+use constructor_lite::ConstructorLite;
+
+#[derive(Debug, PartialEq, ConstructorLite)]
+#[constructor(into)]
+struct Movie {
+    title: String,
+    year: Option<u16>,
+}
+
+#[test]
+fn test_into() {
+    assert_eq!(
+        Movie::new("Star Wars"),
+        Movie {
+            title: "Star Wars".to_owned(),
+            year: None
+        }
+    )
+}
+
+#[derive(Debug, PartialEq, ConstructorLite)]
+struct Song {
+    title: String,
+    #[constructor(into)]
+    #[constructor(required)]
+    duration_secs: u32,
+}
+
+#[test]
+fn test_into_single_field() {
+    assert_eq!(
+        Song::new("Across the Universe".to_owned(), 395u16),
+        Song {
+            title: "Across the Universe".to_owned(),
+            duration_secs: 395
+        }
+    )
+}
@@ -0,0 +1,37 @@
+use constructor_lite::ConstructorLite;
+
+#[derive(Debug, PartialEq, ConstructorLite)]
+#[constructor(ctor(name = "new"))]
+#[constructor(ctor(name = "from_title"))]
+#[constructor(ctor(name = "empty"))]
+struct Movie {
+    #[constructor(required(from_title))]
+    #[constructor(default(empty))]
+    title: String,
+    year: Option<u16>,
+}
+
+#[test]
+fn test_multiple_constructors() {
+    assert_eq!(
+        Movie::new("Star Wars".to_owned()),
+        Movie {
+            title: "Star Wars".to_owned(),
+            year: None
+        }
+    );
+    assert_eq!(
+        Movie::from_title("Star Wars".to_owned()),
+        Movie {
+            title: "Star Wars".to_owned(),
+            year: None
+        }
+    );
+    assert_eq!(
+        Movie::empty(),
+        Movie {
+            title: "".to_owned(),
+            year: None
+        }
+    );
+}
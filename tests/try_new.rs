@@ -0,0 +1,59 @@
+use std::num::TryFromIntError;
+
+use constructor_lite::ConstructorLite;
+
+#[derive(Debug)]
+enum MovieError {
+    InvalidYear(TryFromIntError),
+    OutOfRange,
+}
+impl From<TryFromIntError> for MovieError {
+    fn from(err: TryFromIntError) -> Self {
+        MovieError::InvalidYear(err)
+    }
+}
+
+fn validate_year(movie: &Movie) -> Result<(), MovieError> {
+    if movie.year < 1888 {
+        Err(MovieError::OutOfRange)
+    } else {
+        Ok(())
+    }
+}
+
+#[derive(Debug, PartialEq, ConstructorLite)]
+#[constructor(error = "MovieError")]
+#[constructor(validate = "validate_year")]
+struct Movie {
+    title: String,
+    #[constructor(try_into)]
+    year: u16,
+}
+
+#[test]
+fn test_try_new_ok() {
+    let movie = Movie::try_new("Star Wars".to_owned(), 1977u32).unwrap();
+    assert_eq!(
+        movie,
+        Movie {
+            title: "Star Wars".to_owned(),
+            year: 1977
+        }
+    );
+}
+
+#[test]
+fn test_try_new_try_into_err() {
+    assert!(matches!(
+        Movie::try_new("Star Wars".to_owned(), -1i32),
+        Err(MovieError::InvalidYear(_))
+    ));
+}
+
+#[test]
+fn test_try_new_validate_err() {
+    assert!(matches!(
+        Movie::try_new("Star Wars".to_owned(), 1000u32),
+        Err(MovieError::OutOfRange)
+    ));
+}
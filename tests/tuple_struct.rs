@@ -0,0 +1,9 @@
+use constructor_lite::ConstructorLite;
+
+#[derive(Debug, PartialEq, ConstructorLite)]
+struct Point(f64, f64, Option<&'static str>);
+
+#[test]
+fn test_tuple_struct() {
+    assert_eq!(Point::new(1.0, 2.0), Point(1.0, 2.0, None))
+}